@@ -11,12 +11,51 @@ use std::time::{Duration, Instant};
 
 const SCREEN_WIDTH: usize = 800;
 const SCREEN_HEIGHT: usize = 600;
-const CELL_SIZE: usize = 20;
-const GRID_WIDTH: usize = SCREEN_WIDTH / CELL_SIZE;
-const GRID_HEIGHT: usize = SCREEN_HEIGHT / CELL_SIZE;
+
+// The arena is a world in its own right, decoupled from the viewport: it can be
+// larger than the window, in which case the camera follows the snake around it.
+const ARENA_WIDTH: usize = 120;
+const ARENA_HEIGHT: usize = 90;
+const GRID_WIDTH: usize = ARENA_WIDTH;
+const GRID_HEIGHT: usize = ARENA_HEIGHT;
+
+// Number of AI-controlled opponent snakes sharing the arena with the player.
+const OPPONENT_COUNT: usize = 2;
+
+// Default zoom level; `Renderer::cell_size` is a runtime field, adjusted in
+// steps of `ZOOM_STEP` by the +/- keys, so large arenas can be zoomed out to
+// fit more of the world on screen.
+const DEFAULT_CELL_SIZE: usize = 20;
+const MIN_CELL_SIZE: usize = 4;
+const MAX_CELL_SIZE: usize = 40;
+const ZOOM_STEP: usize = 2;
+
 const TICK_DURATION: Duration = Duration::from_millis(100);
 const TARGET_FPS: u64 = 60;
 
+// Sentinel label marking an obstacle cell in the flood-fill kernel's grid buffer.
+const FLOOD_FILL_SENTINEL: u32 = u32::MAX;
+
+// Potential-field cell kinds, used by the pheromone diffusion kernel to pin the
+// apple (source) and snake body (obstacle) cells across diffusion passes.
+const PHEROMONE_CELL_FREE: u32 = 0;
+const PHEROMONE_CELL_OBSTACLE: u32 = 1;
+const PHEROMONE_CELL_SOURCE: u32 = 2;
+const PHEROMONE_MAX_ATTRACTANT: f32 = 1.0;
+const PHEROMONE_DECAY: f32 = 0.99;
+
+// Scoring and lifetime for each food kind; a normal apple never expires and
+// is always kept topped up to one, while bonus/shrink food trickle in on
+// `FoodSpawnTimer` and despawn if nobody eats them in time.
+const FOOD_SCORE_NORMAL: u32 = 1;
+const FOOD_SCORE_BONUS: u32 = 5;
+const FOOD_SCORE_SHRINK: u32 = 0;
+const FOOD_SHRINK_SEGMENTS: usize = 2;
+const FOOD_SPAWN_INTERVAL: Duration = Duration::from_secs(8);
+const FOOD_BONUS_LIFETIME: Duration = Duration::from_secs(6);
+const FOOD_SHRINK_LIFETIME: Duration = Duration::from_secs(6);
+const FOOD_BONUS_CHANCE: f64 = 0.7;
+
 // ============================================================================
 // TYPES - Basic Building Blocks
 // ============================================================================
@@ -115,9 +154,198 @@ impl PartialOrd for Node {
     }
 }
 
+// ============================================================================
+// GPU FLOOD-FILL KERNEL - Parallel Label Propagation
+// ============================================================================
+
+// One pass of label propagation: every non-obstacle cell takes the smallest label
+// among itself and its four toroidal neighbors. After k passes a cell holds the
+// smallest linear index within k steps of it inside its connected region.
+// Obstacle cells are seeded with `FLOOD_FILL_SENTINEL` and never propagate a label.
+#[cube(launch)]
+fn flood_fill_kernel(
+    labels_in: &Array<u32>,
+    labels_out: &mut Array<u32>,
+    width: u32,
+    height: u32,
+) {
+    let idx = ABSOLUTE_POS;
+
+    if idx < width * height {
+        let label = labels_in[idx];
+
+        if label == 4294967295u32 {
+            labels_out[idx] = label;
+        } else {
+            let x = idx % width;
+            let y = idx / width;
+
+            let left_x = (x + width - 1u32) % width;
+            let right_x = (x + 1u32) % width;
+            let up_y = (y + height - 1u32) % height;
+            let down_y = (y + 1u32) % height;
+
+            let left = labels_in[y * width + left_x];
+            let right = labels_in[y * width + right_x];
+            let up = labels_in[up_y * width + x];
+            let down = labels_in[down_y * width + x];
+
+            let mut best = label;
+            if left < best {
+                best = left;
+            }
+            if right < best {
+                best = right;
+            }
+            if up < best {
+                best = up;
+            }
+            if down < best {
+                best = down;
+            }
+
+            labels_out[idx] = best;
+        }
+    }
+}
+
+// ============================================================================
+// GPU POTENTIAL-FIELD KERNEL - Pheromone-Style Diffusion
+// ============================================================================
+
+// Diffuses an attractant field from the apple across free cells, decaying each
+// pass so the field forms a gradient the AI can climb. Source and obstacle cells
+// are re-pinned every pass via `cell_kind` so they don't get overwritten by their
+// neighbors' diffused values.
+#[cube(launch)]
+fn potential_diffusion_kernel(
+    field_in: &Array<f32>,
+    field_out: &mut Array<f32>,
+    cell_kind: &Array<u32>,
+    width: u32,
+    height: u32,
+    max_attractant: f32,
+    decay: f32,
+) {
+    let idx = ABSOLUTE_POS;
+
+    if idx < width * height {
+        let kind = cell_kind[idx];
+
+        if kind == 1u32 {
+            field_out[idx] = 0.0;
+        } else if kind == 2u32 {
+            field_out[idx] = max_attractant;
+        } else {
+            let x = idx % width;
+            let y = idx / width;
+
+            let left_x = (x + width - 1u32) % width;
+            let right_x = (x + 1u32) % width;
+            let up_y = (y + height - 1u32) % height;
+            let down_y = (y + 1u32) % height;
+
+            let left = field_in[y * width + left_x];
+            let right = field_in[y * width + right_x];
+            let up = field_in[up_y * width + x];
+            let down = field_in[down_y * width + x];
+
+            let mut best = left;
+            if right > best {
+                best = right;
+            }
+            if up > best {
+                best = up;
+            }
+            if down > best {
+                best = down;
+            }
+
+            field_out[idx] = decay * best;
+        }
+    }
+}
+
 struct AIAgent;
 
 impl AIAgent {
+    // Uploads the given obstacle set and runs the flood-fill kernel for a fixed
+    // `GRID_WIDTH + GRID_HEIGHT` passes, swapping buffers on-device between
+    // passes, then syncs and reads back the final per-cell label grid once —
+    // mirroring how `compute_potential_field` drives `potential_diffusion_kernel`,
+    // instead of paying for a device round-trip on every single pass. There is
+    // no convergence check: a region whose shortest-path diameter exceeds the
+    // pass count (e.g. a serpentine corridor between bodies) does not settle on
+    // a single label, and `reachable_count` then counts only part of it.
+    //
+    // Callers that need the reachable count for several start cells against the
+    // *same* obstacle set (e.g. scoring every candidate direction) should call
+    // this once and look each one up via `reachable_count`, rather than paying
+    // for a full fill per start cell.
+    fn flood_fill_labels<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        obstacles: &HashSet<Position>,
+    ) -> Vec<u32> {
+        let cell_count = GRID_WIDTH * GRID_HEIGHT;
+
+        let mut seed = vec![0u32; cell_count];
+        for y in 0..GRID_HEIGHT {
+            for x in 0..GRID_WIDTH {
+                let idx = y * GRID_WIDTH + x;
+                let pos = Position::new(x as u32, y as u32);
+                seed[idx] = if obstacles.contains(&pos) {
+                    FLOOD_FILL_SENTINEL
+                } else {
+                    idx as u32
+                };
+            }
+        }
+
+        let mut buffer_a = client.create(bytemuck::cast_slice(&seed));
+        let mut buffer_b = client.empty(cell_count * std::mem::size_of::<u32>());
+
+        let cube_count = CubeCount::Static(((cell_count + 255) / 256) as u32, 1, 1);
+        let cube_dim = CubeDim::new(256, 1, 1);
+        let passes = GRID_WIDTH + GRID_HEIGHT;
+
+        for _ in 0..passes {
+            flood_fill_kernel::launch::<R>(
+                client,
+                cube_count,
+                cube_dim,
+                unsafe { ArrayArg::from_raw_parts::<u32>(&buffer_a, cell_count, 1) },
+                unsafe { ArrayArg::from_raw_parts::<u32>(&buffer_b, cell_count, 1) },
+                ScalarArg::new(GRID_WIDTH as u32),
+                ScalarArg::new(GRID_HEIGHT as u32),
+            );
+
+            std::mem::swap(&mut buffer_a, &mut buffer_b);
+        }
+
+        pollster::block_on(client.sync());
+
+        let data = client.read(vec![buffer_a.clone()]);
+        bytemuck::cast_slice(&data[0]).to_vec()
+    }
+
+    // Counts cells sharing `start`'s final label in a label grid produced by
+    // `flood_fill_labels`.
+    fn reachable_count(final_labels: &[u32], start: Position) -> usize {
+        let start_idx = start.y as usize * GRID_WIDTH + start.x as usize;
+        let start_label = final_labels[start_idx];
+
+        final_labels.iter().filter(|&&label| label == start_label).count()
+    }
+
+    fn flood_fill<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        start: Position,
+        obstacles: &HashSet<Position>,
+    ) -> usize {
+        let final_labels = Self::flood_fill_labels::<R>(client, obstacles);
+        Self::reachable_count(&final_labels, start)
+    }
+
     fn find_path(start: Position, goal: Position, obstacles: &HashSet<Position>) -> Option<Vec<Direction>> {
         let mut open_set = BinaryHeap::new();
         let mut closed_set = HashSet::new();
@@ -168,37 +396,43 @@ impl AIAgent {
         None
     }
 
-    fn flood_fill(start: Position, obstacles: &HashSet<Position>) -> usize {
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
-        queue.push_back(start);
-        visited.insert(start);
-
-        while let Some(pos) = queue.pop_front() {
-            for (_, neighbor) in pos.neighbors() {
-                if !obstacles.contains(&neighbor) && !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back(neighbor);
-                }
-            }
-        }
-
-        visited.len()
-    }
+    // `obstacles` is the union of every living snake's body (including this
+    // snake's own), so the safety and pathing checks below naturally treat
+    // opponents the same way they treat self-collision.
+    fn is_safe_move<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        next_pos: Position,
+        head: Position,
+        own_body_len: usize,
+        obstacles: &HashSet<Position>,
+    ) -> bool {
+        let mut future_obstacles = obstacles.clone();
+        future_obstacles.remove(&head);
 
-    fn is_safe_move(next_pos: Position, snake_body: &VecDeque<Position>) -> bool {
-        let mut future_obstacles: HashSet<Position> = snake_body.iter().skip(1).copied().collect();
-        
         // Simulate the move
-        let reachable = Self::flood_fill(next_pos, &future_obstacles);
-        
+        let reachable = Self::flood_fill::<R>(client, next_pos, &future_obstacles);
+
         // Need enough space for the snake to move
-        reachable > snake_body.len()
+        reachable > own_body_len
     }
 
-    fn find_safest_direction(head: Position, snake_body: &VecDeque<Position>, current_dir: Direction) -> Direction {
-        let obstacles: HashSet<Position> = snake_body.iter().copied().collect();
-        
+    fn find_safest_direction<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        head: Position,
+        own_tail: Position,
+        current_dir: Direction,
+        obstacles: &HashSet<Position>,
+    ) -> Direction {
+        // The tail frees up regardless of which direction we pick, so every
+        // candidate shares the same obstacle set: flood-fill it once and look
+        // up each direction's reachable space instead of re-running the whole
+        // fill per direction. Candidates are never themselves marked as
+        // obstacles here, since we want their own region counted, not the
+        // sentinel-seeded count of all obstacle cells.
+        let mut temp_obstacles = obstacles.clone();
+        temp_obstacles.remove(&own_tail);
+        let final_labels = Self::flood_fill_labels::<R>(client, &temp_obstacles);
+
         // Score each direction
         let mut best_dir = current_dir;
         let mut best_score = 0;
@@ -209,17 +443,13 @@ impl AIAgent {
             }
 
             let next_pos = head.move_by(dir);
-            
+
             if obstacles.contains(&next_pos) {
                 continue;
             }
 
-            let mut temp_obstacles = obstacles.clone();
-            temp_obstacles.remove(snake_body.back().unwrap());
-            temp_obstacles.insert(next_pos);
-            
-            let space = Self::flood_fill(next_pos, &temp_obstacles);
-            
+            let space = Self::reachable_count(&final_labels, next_pos);
+
             if space > best_score {
                 best_score = space;
                 best_dir = dir;
@@ -229,29 +459,178 @@ impl AIAgent {
         best_dir
     }
 
-    fn decide(game: &GameState) -> Direction {
-        let head = game.snake.head();
-        let apple = game.apple;
-        let snake_body = &game.snake.body;
-        let current_dir = game.snake.direction;
+    // Picks the most valuable reachable food to chase: score weighted by the
+    // A* path length (farther food is worth less) and, for food with an
+    // expiry, by how much time is left to actually reach it. Shrink food is
+    // never targeted on purpose.
+    fn choose_target_food(
+        head: Position,
+        foods: &[Food],
+        obstacles: &HashSet<Position>,
+    ) -> Option<Position> {
+        let now = Instant::now();
+
+        foods
+            .iter()
+            .filter(|food| food.kind != FoodKind::Shrink)
+            .filter_map(|food| {
+                let path_len = Self::find_path(head, food.pos, obstacles)?.len() as f32;
+
+                let urgency = match food.expires_at {
+                    None => 1.0,
+                    Some(expires_at) => {
+                        let remaining = expires_at.saturating_duration_since(now).as_secs_f32();
+                        if remaining <= 0.0 {
+                            0.0
+                        } else {
+                            remaining / (remaining + path_len)
+                        }
+                    }
+                };
 
-        // Build obstacle set (snake body)
-        let obstacles: HashSet<Position> = snake_body.iter().copied().collect();
+                let score = food.kind.score() as f32 * urgency / (1.0 + path_len);
+                Some((score, food.pos))
+            })
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .map(|(_, pos)| pos)
+    }
 
-        // Try to find path to apple
-        if let Some(path) = Self::find_path(head, apple, &obstacles) {
-            if let Some(&first_move) = path.first() {
-                let next_pos = head.move_by(first_move);
-                
-                // Check if this move is safe (doesn't trap us)
-                if Self::is_safe_move(next_pos, snake_body) {
-                    return first_move;
+    fn decide<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        head: Position,
+        body: &VecDeque<Position>,
+        current_dir: Direction,
+        foods: &[Food],
+        obstacles: &HashSet<Position>,
+    ) -> Direction {
+        // Try to find a path to the best reachable food
+        if let Some(target) = Self::choose_target_food(head, foods, obstacles) {
+            if let Some(path) = Self::find_path(head, target, obstacles) {
+                if let Some(&first_move) = path.first() {
+                    let next_pos = head.move_by(first_move);
+
+                    // Check if this move is safe (doesn't trap us)
+                    if Self::is_safe_move::<R>(client, next_pos, head, body.len(), obstacles) {
+                        return first_move;
+                    }
                 }
             }
         }
 
-        // If no safe path to apple, find safest direction to maximize space
-        Self::find_safest_direction(head, snake_body, current_dir)
+        // If no safe path to food, find safest direction to maximize space
+        Self::find_safest_direction::<R>(client, head, *body.back().unwrap(), current_dir, obstacles)
+    }
+
+    // Diffuses an attractant field from `source` (the chosen food target) around
+    // the snake body (obstacles) and returns it as a row-major
+    // `GRID_WIDTH * GRID_HEIGHT` buffer.
+    fn compute_potential_field<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        source: Position,
+        obstacles: &HashSet<Position>,
+    ) -> Vec<f32> {
+        let cell_count = GRID_WIDTH * GRID_HEIGHT;
+
+        let mut field = vec![0.0f32; cell_count];
+        let mut cell_kind = vec![PHEROMONE_CELL_FREE; cell_count];
+        for y in 0..GRID_HEIGHT {
+            for x in 0..GRID_WIDTH {
+                let idx = y * GRID_WIDTH + x;
+                let pos = Position::new(x as u32, y as u32);
+                if pos == source {
+                    cell_kind[idx] = PHEROMONE_CELL_SOURCE;
+                    field[idx] = PHEROMONE_MAX_ATTRACTANT;
+                } else if obstacles.contains(&pos) {
+                    cell_kind[idx] = PHEROMONE_CELL_OBSTACLE;
+                }
+            }
+        }
+
+        let mut buffer_a = client.create(bytemuck::cast_slice(&field));
+        let mut buffer_b = client.empty(cell_count * std::mem::size_of::<f32>());
+        let kind_buffer = client.create(bytemuck::cast_slice(&cell_kind));
+
+        let cube_count = CubeCount::Static(((cell_count + 255) / 256) as u32, 1, 1);
+        let cube_dim = CubeDim::new(256, 1, 1);
+        let passes = GRID_WIDTH + GRID_HEIGHT;
+
+        for _ in 0..passes {
+            potential_diffusion_kernel::launch::<R>(
+                client,
+                cube_count,
+                cube_dim,
+                unsafe { ArrayArg::from_raw_parts::<f32>(&buffer_a, cell_count, 1) },
+                unsafe { ArrayArg::from_raw_parts::<f32>(&buffer_b, cell_count, 1) },
+                unsafe { ArrayArg::from_raw_parts::<u32>(&kind_buffer, cell_count, 1) },
+                ScalarArg::new(GRID_WIDTH as u32),
+                ScalarArg::new(GRID_HEIGHT as u32),
+                ScalarArg::new(PHEROMONE_MAX_ATTRACTANT),
+                ScalarArg::new(PHEROMONE_DECAY),
+            );
+            std::mem::swap(&mut buffer_a, &mut buffer_b);
+        }
+
+        pollster::block_on(client.sync());
+
+        let data = client.read(vec![buffer_a.clone()]);
+        bytemuck::cast_slice(&data[0]).to_vec()
+    }
+
+    // Follows the potential field's gradient: among legal, non-reversing
+    // neighbors, step toward the highest field value. Falls back to
+    // `find_safest_direction` when every neighbor reads zero (no gradient yet,
+    // e.g. the field hasn't diffused far enough or the snake is boxed in) —
+    // now that `find_safest_direction` flood-fills the shared obstacle set
+    // once instead of per direction, this fallback actually maximizes space
+    // again instead of degenerating to the first legal direction.
+    fn decide_pheromone<R: Runtime>(
+        client: &ComputeClient<R::Server>,
+        head: Position,
+        body: &VecDeque<Position>,
+        current_dir: Direction,
+        foods: &[Food],
+        obstacles: &HashSet<Position>,
+    ) -> Direction {
+        let target = match Self::choose_target_food(head, foods, obstacles) {
+            Some(target) => target,
+            None => {
+                return Self::find_safest_direction::<R>(
+                    client,
+                    head,
+                    *body.back().unwrap(),
+                    current_dir,
+                    obstacles,
+                )
+            }
+        };
+
+        let field = Self::compute_potential_field::<R>(client, target, obstacles);
+
+        let mut best_dir = None;
+        let mut best_value = 0.0f32;
+
+        for &dir in Direction::all().iter() {
+            if dir == current_dir.opposite() {
+                continue;
+            }
+
+            let next_pos = head.move_by(dir);
+            if obstacles.contains(&next_pos) {
+                continue;
+            }
+
+            let idx = next_pos.y as usize * GRID_WIDTH + next_pos.x as usize;
+            let value = field[idx];
+
+            if value > best_value {
+                best_value = value;
+                best_dir = Some(dir);
+            }
+        }
+
+        best_dir.unwrap_or_else(|| {
+            Self::find_safest_direction::<R>(client, head, *body.back().unwrap(), current_dir, obstacles)
+        })
     }
 }
 
@@ -259,23 +638,125 @@ impl AIAgent {
 // GAME STATE - Core Logic
 // ============================================================================
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FoodKind {
+    Normal,
+    Bonus,
+    Shrink,
+}
+
+impl FoodKind {
+    fn score(self) -> u32 {
+        match self {
+            FoodKind::Normal => FOOD_SCORE_NORMAL,
+            FoodKind::Bonus => FOOD_SCORE_BONUS,
+            FoodKind::Shrink => FOOD_SCORE_SHRINK,
+        }
+    }
+
+    // Normal and bonus food make the snake grow by skipping the per-tick tail
+    // shrink; shrink food pops extra segments on top of that tail shrink.
+    fn grows(self) -> bool {
+        matches!(self, FoodKind::Normal | FoodKind::Bonus)
+    }
+
+    fn lifetime(self) -> Option<Duration> {
+        match self {
+            FoodKind::Normal => None,
+            FoodKind::Bonus => Some(FOOD_BONUS_LIFETIME),
+            FoodKind::Shrink => Some(FOOD_SHRINK_LIFETIME),
+        }
+    }
+
+    fn shrink_segments(self) -> usize {
+        if self == FoodKind::Shrink {
+            FOOD_SHRINK_SEGMENTS
+        } else {
+            0
+        }
+    }
+}
+
+struct Food {
+    pos: Position,
+    kind: FoodKind,
+    expires_at: Option<Instant>,
+}
+
+impl Food {
+    fn new(pos: Position, kind: FoodKind) -> Self {
+        Self {
+            pos,
+            expires_at: kind.lifetime().map(|lifetime| Instant::now() + lifetime),
+            kind,
+        }
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+// Fires on a fixed interval independent of eating, introducing a new bonus or
+// shrink food into the arena.
+struct FoodSpawnTimer {
+    interval: Duration,
+    last_spawn: Instant,
+}
+
+impl FoodSpawnTimer {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_spawn: Instant::now(),
+        }
+    }
+
+    fn ready(&self) -> bool {
+        self.last_spawn.elapsed() >= self.interval
+    }
+
+    fn reset(&mut self) {
+        self.last_spawn = Instant::now();
+    }
+}
+
 struct Snake {
+    id: u32,
     body: VecDeque<Position>,
     direction: Direction,
     next_direction: Direction,
+    controller: GameMode,
+    alive: bool,
+    score: u32,
 }
 
 impl Snake {
-    fn new(head: Position) -> Self {
+    fn new(head: Position, id: u32, controller: GameMode) -> Self {
+        let direction = Direction::Right;
+
+        // `saturating_sub` would clamp trailing segments onto the head cell
+        // itself for a spawn at x==0 or x==1, producing a degenerate
+        // multi-segment snake occupying one or two cells. The grid wraps
+        // toroidally (see `Position::move_by`), so walk backwards along the
+        // spawn direction instead — every trailing segment lands on a
+        // distinct cell no matter where the head spawns.
         let mut body = VecDeque::new();
         body.push_back(head);
-        body.push_back(Position::new(head.x.saturating_sub(1), head.y));
-        body.push_back(Position::new(head.x.saturating_sub(2), head.y));
-        
+        let mut tail = head;
+        for _ in 0..2 {
+            tail = tail.move_by(direction.opposite());
+            body.push_back(tail);
+        }
+
         Self {
+            id,
             body,
-            direction: Direction::Right,
-            next_direction: Direction::Right,
+            direction,
+            next_direction: direction,
+            controller,
+            alive: true,
+            score: 0,
         }
     }
 
@@ -300,15 +781,29 @@ impl Snake {
         self.body.pop_back();
     }
 
+    // Pops up to `count` extra tail segments (shrink food), never leaving the
+    // snake without a head.
+    fn shrink_by(&mut self, count: usize) {
+        for _ in 0..count {
+            if self.body.len() <= 1 {
+                break;
+            }
+            self.body.pop_back();
+        }
+    }
+
     fn contains(&self, pos: Position) -> bool {
         self.body.iter().any(|&p| p == pos)
     }
 
+    // Emits `(x, y, snake_id)` triples so the renderer can give every snake its
+    // own hue from a single packed segment buffer.
     fn serialize(&self) -> Vec<u32> {
-        let mut data = Vec::with_capacity(self.body.len() * 2);
+        let mut data = Vec::with_capacity(self.body.len() * 3);
         for pos in &self.body {
             data.push(pos.x);
             data.push(pos.y);
+            data.push(self.id);
         }
         data
     }
@@ -318,12 +813,13 @@ impl Snake {
 enum GameMode {
     Human,
     AI,
+    Pheromone,
 }
 
 struct GameState {
-    snake: Snake,
-    apple: Position,
-    score: u32,
+    snakes: Vec<Snake>,
+    foods: Vec<Food>,
+    food_spawn_timer: FoodSpawnTimer,
     game_over: bool,
     last_tick: Instant,
     mode: GameMode,
@@ -331,26 +827,96 @@ struct GameState {
 
 impl GameState {
     fn new(mode: GameMode) -> Self {
+        let mut snakes = vec![Snake::new(
+            Position::new(GRID_WIDTH as u32 / 2, GRID_HEIGHT as u32 / 2),
+            0,
+            mode,
+        )];
+
+        for i in 0..OPPONENT_COUNT {
+            let id = (i + 1) as u32;
+            let head = Position::new(
+                (GRID_WIDTH as u32 / (OPPONENT_COUNT as u32 + 1)) * id,
+                (GRID_HEIGHT as u32 / (OPPONENT_COUNT as u32 + 1)) * (OPPONENT_COUNT as u32 + 1 - id),
+            );
+            snakes.push(Snake::new(head, id, GameMode::AI));
+        }
+
         Self {
-            snake: Snake::new(Position::new(
-                GRID_WIDTH as u32 / 2,
-                GRID_HEIGHT as u32 / 2,
-            )),
-            apple: Position::new(10, 10),
-            score: 0,
+            snakes,
+            foods: vec![Food::new(Position::new(10, 10), FoodKind::Normal)],
+            food_spawn_timer: FoodSpawnTimer::new(FOOD_SPAWN_INTERVAL),
             game_over: false,
             last_tick: Instant::now(),
             mode,
         }
     }
 
+    fn player(&self) -> &Snake {
+        &self.snakes[0]
+    }
+
+    fn player_score(&self) -> u32 {
+        self.snakes[0].score
+    }
+
     fn handle_input(&mut self, input: Input) {
         if let Some(dir) = input.direction {
-            self.snake.set_direction(dir);
+            self.snakes[0].set_direction(dir);
+        }
+    }
+
+    // Union of every living snake's body; each AI snake treats opponents and
+    // itself as the same kind of obstacle.
+    fn all_obstacles(&self) -> HashSet<Position> {
+        self.snakes
+            .iter()
+            .filter(|s| s.alive)
+            .flat_map(|s| s.body.iter().copied())
+            .collect()
+    }
+
+    // Lets every non-human, living snake pick its next move. Called once per
+    // tick, before `tick()` advances anyone, so all snakes act on the same
+    // snapshot of the arena.
+    fn update_ai<R: Runtime>(&mut self, client: &ComputeClient<R::Server>) {
+        let obstacles = self.all_obstacles();
+
+        for snake in &mut self.snakes {
+            if !snake.alive || snake.controller == GameMode::Human {
+                continue;
+            }
+
+            let head = snake.head();
+            let current_dir = snake.direction;
+
+            let action = match snake.controller {
+                GameMode::AI => AIAgent::decide::<R>(
+                    client,
+                    head,
+                    &snake.body,
+                    current_dir,
+                    &self.foods,
+                    &obstacles,
+                ),
+                GameMode::Pheromone => AIAgent::decide_pheromone::<R>(
+                    client,
+                    head,
+                    &snake.body,
+                    current_dir,
+                    &self.foods,
+                    &obstacles,
+                ),
+                GameMode::Human => unreachable!(),
+            };
+
+            snake.set_direction(action);
         }
     }
 
     fn tick(&mut self) {
+        use rand::Rng;
+
         if self.game_over {
             return;
         }
@@ -360,41 +926,139 @@ impl GameState {
         }
         self.last_tick = Instant::now();
 
-        let new_head = self.snake.advance();
+        for snake in &mut self.snakes {
+            if snake.alive {
+                snake.advance();
+            }
+        }
+
+        // Check collisions (self and head-to-head/head-to-body against every
+        // other living snake) before anyone shrinks back down.
+        let mut died = Vec::new();
+        for i in 0..self.snakes.len() {
+            if !self.snakes[i].alive {
+                continue;
+            }
+
+            let id = self.snakes[i].id;
+            let new_head = self.snakes[i].head();
 
-        // Check collision with self
-        if self.snake.body.iter().skip(1).any(|&p| p == new_head) {
-            self.game_over = true;
-            println!("ðŸ’€ Game Over! Final Score: {}", self.score);
-            return;
+            let collided = self.snakes.iter().any(|other| {
+                if !other.alive {
+                    return false;
+                }
+                // Skip the segment we just moved into on our own body; every
+                // other snake's segments (including its new head) are solid.
+                let skip = if other.id == id { 1 } else { 0 };
+                other.body.iter().skip(skip).any(|&p| p == new_head)
+            });
+
+            if collided {
+                died.push(i);
+            }
         }
 
-        // Check apple collision
-        if new_head == self.apple {
-            self.score += 1;
-            self.spawn_apple();
-            println!("ðŸŽ Score: {}", self.score);
-        } else {
-            self.snake.shrink();
+        for &i in &died {
+            self.snakes[i].alive = false;
+        }
+
+        // Despawn anything that expired before anyone got to it.
+        let now = Instant::now();
+        self.foods.retain(|food| !food.is_expired(now));
+
+        let mut normal_eaten = false;
+        for snake in &mut self.snakes {
+            if !snake.alive {
+                continue;
+            }
+
+            let eaten_index = self.foods.iter().position(|food| food.pos == snake.head());
+            match eaten_index {
+                Some(index) => {
+                    let food = self.foods.remove(index);
+                    snake.score += food.kind.score();
+                    normal_eaten |= food.kind == FoodKind::Normal;
+
+                    if !food.kind.grows() {
+                        snake.shrink();
+                    }
+                    snake.shrink_by(food.kind.shrink_segments());
+
+                    if food.kind.score() > 0 && snake.id == 0 {
+                        println!("ðŸŽ Score: {}", snake.score);
+                    }
+                }
+                None => snake.shrink(),
+            }
+        }
+
+        if normal_eaten || !self.foods.iter().any(|food| food.kind == FoodKind::Normal) {
+            self.spawn_food(FoodKind::Normal);
+        }
+
+        if self.food_spawn_timer.ready() {
+            self.food_spawn_timer.reset();
+            let kind = if rand::thread_rng().gen_bool(FOOD_BONUS_CHANCE) {
+                FoodKind::Bonus
+            } else {
+                FoodKind::Shrink
+            };
+            self.spawn_food(kind);
+        }
+
+        for i in died {
+            if self.snakes[i].id == 0 {
+                self.game_over = true;
+                println!("ðŸ’€ Game Over! Final Score: {}", self.snakes[i].score);
+            } else {
+                let id = self.snakes[i].id;
+                let controller = self.snakes[i].controller;
+                let spawn_at = self.free_spawn_position();
+                self.snakes[i] = Snake::new(spawn_at, id, controller);
+            }
         }
     }
 
-    fn spawn_apple(&mut self) {
+    fn free_spawn_position(&self) -> Position {
         use rand::Rng;
         let mut rng = rand::thread_rng();
-        
+
         loop {
             let pos = Position::new(
                 rng.gen_range(0..GRID_WIDTH as u32),
                 rng.gen_range(0..GRID_HEIGHT as u32),
             );
-            
-            if !self.snake.contains(pos) {
-                self.apple = pos;
-                break;
+
+            let occupied = self.foods.iter().any(|food| food.pos == pos)
+                || self.snakes.iter().any(|s| s.alive && s.contains(pos));
+            if !occupied {
+                return pos;
             }
         }
     }
+
+    fn spawn_food(&mut self, kind: FoodKind) {
+        let pos = self.free_spawn_position();
+        self.foods.push(Food::new(pos, kind));
+    }
+
+    // Packs every living snake's segments into one `(x, y, snake_id)` buffer
+    // for the renderer.
+    fn serialize_snakes(&self) -> Vec<u32> {
+        self.snakes
+            .iter()
+            .filter(|s| s.alive)
+            .flat_map(|s| s.serialize())
+            .collect()
+    }
+
+    // Packs every live food item into a `(x, y, kind)` buffer for the renderer.
+    fn serialize_foods(&self) -> Vec<u32> {
+        self.foods
+            .iter()
+            .flat_map(|food| [food.pos.x, food.pos.y, food.kind as u32])
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -406,6 +1070,8 @@ struct Input {
     direction: Option<Direction>,
     quit: bool,
     toggle_mode: bool,
+    zoom_in: bool,
+    zoom_out: bool,
 }
 
 impl Input {
@@ -420,6 +1086,12 @@ impl Input {
             input.toggle_mode = true;
         }
 
+        if window.is_key_pressed(Key::Equal, minifb::KeyRepeat::No) {
+            input.zoom_in = true;
+        } else if window.is_key_pressed(Key::Minus, minifb::KeyRepeat::No) {
+            input.zoom_out = true;
+        }
+
         if window.is_key_down(Key::Up) {
             input.direction = Some(Direction::Up);
         } else if window.is_key_down(Key::Down) {
@@ -443,53 +1115,104 @@ fn render_kernel(
     output: &mut Array<f32>,
     snake_data: &Array<u32>,
     snake_length: u32,
-    apple_x: u32,
-    apple_y: u32,
+    food_data: &Array<u32>,
+    food_count: u32,
     width: u32,
     height: u32,
+    arena_width: u32,
+    arena_height: u32,
     cell_size: u32,
-    is_ai: u32,
+    camera_x: i32,
+    camera_y: i32,
 ) {
     let pixel_index = ABSOLUTE_POS;
-    
+
     if pixel_index < width * height {
         let x = pixel_index % width;
         let y = pixel_index / width;
-        let grid_x = x / cell_size;
-        let grid_y = y / cell_size;
+        let world_x = i32::cast_from(x) + camera_x;
+        let world_y = i32::cast_from(y) + camera_y;
+
+        let mut r = 0.05;
+        let mut g = 0.05;
+        let mut b = 0.05;
+
+        if world_x >= 0 && world_y >= 0 {
+            let grid_x = u32::cast_from(world_x) / cell_size;
+            let grid_y = u32::cast_from(world_y) / cell_size;
+
+            if grid_x < arena_width && grid_y < arena_height {
+                r = 0.0;
+                g = 0.4;
+                b = 0.0;
+
+                let mut found_food = false;
+                let mut fi = 0u32;
+                loop {
+                    if fi >= food_count {
+                        break;
+                    }
 
-        let mut r = 0.0;
-        let mut g = 0.4;
-        let mut b = 0.0;
+                    let food_x = food_data[fi * 3u32];
+                    let food_y = food_data[fi * 3u32 + 1u32];
+                    let food_kind = food_data[fi * 3u32 + 2u32];
+
+                    if grid_x == food_x && grid_y == food_y {
+                        found_food = true;
+                        if food_kind == 0u32 {
+                            r = 0.9;
+                            g = 0.0;
+                            b = 0.0;
+                        } else if food_kind == 1u32 {
+                            r = 0.9;
+                            g = 0.9;
+                            b = 0.0;
+                        } else {
+                            r = 0.0;
+                            g = 0.8;
+                            b = 0.8;
+                        }
+                        break;
+                    }
 
-        if grid_x == apple_x && grid_y == apple_y {
-            r = 0.9;
-            g = 0.0;
-            b = 0.0;
-        } else {
-            let mut i = 0u32;
-            loop {
-                if i >= snake_length {
-                    break;
+                    fi += 1u32;
                 }
-                
-                let snake_x = snake_data[i * 2u32];
-                let snake_y = snake_data[i * 2u32 + 1u32];
-                
-                if grid_x == snake_x && grid_y == snake_y {
-                    if is_ai == 1u32 {
-                        r = 0.0;
-                        g = 0.5;
-                        b = 0.9;
-                    } else {
-                        r = 0.0;
-                        g = 0.0;
-                        b = 0.0;
+
+                if !found_food {
+                    let mut i = 0u32;
+                    loop {
+                        if i >= snake_length {
+                            break;
+                        }
+
+                        let snake_x = snake_data[i * 3u32];
+                        let snake_y = snake_data[i * 3u32 + 1u32];
+                        let snake_id = snake_data[i * 3u32 + 2u32];
+
+                        if grid_x == snake_x && grid_y == snake_y {
+                            if snake_id == 0u32 {
+                                r = 0.0;
+                                g = 0.0;
+                                b = 0.0;
+                            } else if snake_id == 1u32 {
+                                r = 0.0;
+                                g = 0.5;
+                                b = 0.9;
+                            } else if snake_id == 2u32 {
+                                r = 0.9;
+                                g = 0.6;
+                                b = 0.0;
+                            } else {
+                                r = 0.8;
+                                g = 0.0;
+                                b = 0.8;
+                            }
+                            break;
+                        }
+
+                        i += 1u32;
                     }
-                    break;
                 }
-                
-                i += 1u32;
             }
         }
 
@@ -500,34 +1223,91 @@ fn render_kernel(
     }
 }
 
+// Follows the snake head around an arena that may be larger than the viewport,
+// clamped so it never scrolls past the arena edges; when an axis of the arena
+// is smaller than the viewport, centers it instead of tracking the head.
+struct Camera {
+    x: i32,
+    y: i32,
+}
+
+impl Camera {
+    fn new() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    fn follow(&mut self, head: Position, cell_size: usize) {
+        self.x = Self::track_axis(head.x as i32, ARENA_WIDTH, cell_size, SCREEN_WIDTH);
+        self.y = Self::track_axis(head.y as i32, ARENA_HEIGHT, cell_size, SCREEN_HEIGHT);
+    }
+
+    fn track_axis(head_cell: i32, arena_cells: usize, cell_size: usize, viewport_px: usize) -> i32 {
+        let arena_px = (arena_cells * cell_size) as i32;
+        let viewport_px = viewport_px as i32;
+
+        if arena_px <= viewport_px {
+            (arena_px - viewport_px) / 2
+        } else {
+            let head_px = head_cell * cell_size as i32;
+            let max_x = arena_px - viewport_px;
+            (head_px - viewport_px / 2).clamp(0, max_x)
+        }
+    }
+}
+
 struct Renderer<R: Runtime> {
     client: ComputeClient<R::Server>,
     frame_buffer: Handle,
+    camera: Camera,
+    cell_size: usize,
 }
 
 impl<R: Runtime> Renderer<R> {
     fn new(client: ComputeClient<R::Server>) -> Self {
         let frame_buffer = client.empty(SCREEN_WIDTH * SCREEN_HEIGHT * 3 * std::mem::size_of::<f32>());
-        Self { client, frame_buffer }
+        Self {
+            client,
+            frame_buffer,
+            camera: Camera::new(),
+            cell_size: DEFAULT_CELL_SIZE,
+        }
     }
 
-    fn render(&self, game: &GameState) -> Vec<u32> {
-        let snake_data = game.snake.serialize();
+    fn zoom_in(&mut self) {
+        self.cell_size = (self.cell_size + ZOOM_STEP).min(MAX_CELL_SIZE);
+    }
+
+    fn zoom_out(&mut self) {
+        self.cell_size = self.cell_size.saturating_sub(ZOOM_STEP).max(MIN_CELL_SIZE);
+    }
+
+    fn render(&mut self, game: &GameState) -> Vec<u32> {
+        self.camera.follow(game.player().head(), self.cell_size);
+
+        let snake_data = game.serialize_snakes();
+        let snake_length = (snake_data.len() / 3) as u32;
         let snake_buffer = self.client.create(bytemuck::cast_slice(&snake_data));
 
+        let food_data = game.serialize_foods();
+        let food_count = (food_data.len() / 3) as u32;
+        let food_buffer = self.client.create(bytemuck::cast_slice(&food_data));
+
         render_kernel::launch::<R>(
             &self.client,
             CubeCount::Static(((SCREEN_WIDTH * SCREEN_HEIGHT + 255) / 256) as u32, 1, 1),
             CubeDim::new(256, 1, 1),
             unsafe { ArrayArg::from_raw_parts::<f32>(&self.frame_buffer, SCREEN_WIDTH * SCREEN_HEIGHT * 3, 1) },
             unsafe { ArrayArg::from_raw_parts::<u32>(&snake_buffer, snake_data.len(), 1) },
-            ScalarArg::new(game.snake.body.len() as u32),
-            ScalarArg::new(game.apple.x),
-            ScalarArg::new(game.apple.y),
+            ScalarArg::new(snake_length),
+            unsafe { ArrayArg::from_raw_parts::<u32>(&food_buffer, food_data.len(), 1) },
+            ScalarArg::new(food_count),
             ScalarArg::new(SCREEN_WIDTH as u32),
             ScalarArg::new(SCREEN_HEIGHT as u32),
-            ScalarArg::new(CELL_SIZE as u32),
-            ScalarArg::new(if game.mode == GameMode::AI { 1u32 } else { 0u32 }),
+            ScalarArg::new(ARENA_WIDTH as u32),
+            ScalarArg::new(ARENA_HEIGHT as u32),
+            ScalarArg::new(self.cell_size as u32),
+            ScalarArg::new(self.camera.x),
+            ScalarArg::new(self.camera.y),
         );
 
         pollster::block_on(self.client.sync());
@@ -558,7 +1338,7 @@ fn main() {
     type Runtime = cubecl::cuda::CudaRuntime;
     
     let client = Runtime::client(&Default::default());
-    let renderer = Renderer::<Runtime>::new(client);
+    let mut renderer = Renderer::<Runtime>::new(client.clone());
     
     let mut window = Window::new(
         "ðŸ Snake - GPU + A* Pathfinding AI",
@@ -577,6 +1357,7 @@ fn main() {
     println!("â• â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•£");
     println!("â•‘  Arrow Keys : Move (Human Mode)       â•‘");
     println!("â•‘  SPACE      : Toggle Human/AI         â•‘");
+    println!("â•‘  +/-        : Zoom In/Out              â•‘");
     println!("â•‘  ESC        : Quit                     â•‘");
     println!("â• â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•£");
     println!("â•‘  AI Strategy:                          â•‘");
@@ -584,7 +1365,7 @@ fn main() {
     println!("â•‘  â€¢ Flood-fill safety checks            â•‘");
     println!("â•‘  â€¢ Space maximization fallback         â•‘");
     println!("â•šâ•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•");
-    println!("\nðŸ‘¤ Mode: HUMAN | Score: {}", game.score);
+    println!("\nðŸ‘¤ Mode: HUMAN | Score: {}", game.player_score());
 
     while window.is_open() {
         let input = Input::from_window(&window);
@@ -600,6 +1381,10 @@ fn main() {
                     GameMode::AI
                 }
                 GameMode::AI => {
+                    println!("\nðŸœ Switched to Pheromone Mode (Potential Field)");
+                    GameMode::Pheromone
+                }
+                GameMode::Pheromone => {
                     println!("\nðŸ‘¤ Switched to Human Mode");
                     GameMode::Human
                 }
@@ -607,25 +1392,27 @@ fn main() {
             game = GameState::new(game.mode);
         }
 
-        match game.mode {
-            GameMode::Human => {
-                game.handle_input(input);
-            }
-            GameMode::AI => {
-                if game.last_tick.elapsed() >= TICK_DURATION {
-                    let action = AIAgent::decide(&game);
-                    game.snake.set_direction(action);
-                }
-            }
+        if input.zoom_in {
+            renderer.zoom_in();
+        } else if input.zoom_out {
+            renderer.zoom_out();
+        }
+
+        if game.mode == GameMode::Human {
+            game.handle_input(input);
+        }
+
+        if game.last_tick.elapsed() >= TICK_DURATION {
+            game.update_ai::<Runtime>(&client);
         }
 
         game.tick();
         let pixels = renderer.render(&game);
-        
+
         window
             .update_with_buffer(&pixels, SCREEN_WIDTH, SCREEN_HEIGHT)
             .expect("Failed to update window");
     }
 
-    println!("\nðŸ‘‹ Thanks for playing! Final Score: {}", game.score);
+    println!("\nðŸ‘‹ Thanks for playing! Final Score: {}", game.player_score());
 }